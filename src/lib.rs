@@ -3,7 +3,7 @@ use pyo3::{
     create_exception,
     exceptions::PyException,
     prelude::*,
-    types::{PyBytes, PyDict, PyString},
+    types::{PyBytes, PyDict},
     wrap_pyfunction,
 };
 use rayon::prelude::*;
@@ -14,27 +14,442 @@ use std::{
 
 create_exception!(pymsyt, MsytError, PyException);
 
+/// Compiles a list of glob pattern strings into `glob::Pattern`s, for matching against paths
+/// relative to the directory being walked.
+fn compile_patterns(patterns: &Option<Vec<String>>) -> PyResult<Option<Vec<glob::Pattern>>> {
+    patterns
+        .as_ref()
+        .map(|patterns| {
+            patterns
+                .iter()
+                .map(|p| {
+                    glob::Pattern::new(p).map_err(|e| {
+                        MsytError::new_err(format!("Invalid glob pattern `{}`: {:?}", p, e))
+                    })
+                })
+                .collect()
+        })
+        .transpose()
+}
+
+/// Invokes a Python progress `callback` with the path (relative to the input directory) just
+/// finished and the running `count`/`total`. The callback runs on a rayon worker thread, so the
+/// GIL is acquired just for this call; keep the callback itself lightweight since it serializes
+/// all of the parallel workers through the GIL.
+fn report_progress(
+    callback: &Option<PyObject>,
+    relative: &Path,
+    count: usize,
+    total: usize,
+) -> PyResult<()> {
+    if let Some(callback) = callback {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        callback
+            .call1(py, (relative.to_string_lossy().into_owned(), count, total))
+            .map_err(|e| {
+                MsytError::new_err(format!("Progress callback raised an exception: {:?}", e))
+            })?;
+    }
+    Ok(())
+}
+
+/// Filters `paths` (relative to `base`) down to those matching at least one `include` pattern
+/// (if any are given) and none of the `exclude` patterns.
+fn filter_paths(
+    paths: Vec<PathBuf>,
+    base: &Path,
+    include: &Option<Vec<glob::Pattern>>,
+    exclude: &Option<Vec<glob::Pattern>>,
+) -> Vec<PathBuf> {
+    paths
+        .into_iter()
+        .filter(|path| {
+            let rel = path.strip_prefix(base).unwrap_or(path);
+            let included = include
+                .as_ref()
+                .map_or(true, |pats| pats.iter().any(|pat| pat.matches_path(rel)));
+            let excluded = exclude
+                .as_ref()
+                .map_or(false, |pats| pats.iter().any(|pat| pat.matches_path(rel)));
+            included && !excluded
+        })
+        .collect()
+}
+
+/// The text/binary formats a `Msyt` can be converted to or from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Yaml,
+    Json,
+    Toml,
+    Cbor,
+}
+
+impl Format {
+    fn parse(format: &str) -> PyResult<Self> {
+        match format.to_lowercase().as_str() {
+            "yaml" | "yml" => Ok(Format::Yaml),
+            "json" => Ok(Format::Json),
+            "toml" => Ok(Format::Toml),
+            "cbor" => Ok(Format::Cbor),
+            _ => Err(MsytError::new_err(format!(
+                "Unknown format `{}`, expected one of: yaml, json, toml, cbor",
+                format
+            ))),
+        }
+    }
+
+    /// Serializes a `Msyt` to bytes in this format. CBOR is a binary format, so unlike the
+    /// text formats its bytes are not valid UTF-8 and should never be treated as a string.
+    fn serialize(self, msyt: &Msyt, pretty: bool) -> PyResult<Vec<u8>> {
+        match self {
+            Format::Yaml => serde_yaml::to_vec(msyt).map_err(|e| {
+                MsytError::new_err(format!("Could not serialize MSBT to YAML: {:?}", e))
+            }),
+            Format::Json => if pretty {
+                serde_json::to_vec_pretty(msyt)
+            } else {
+                serde_json::to_vec(msyt)
+            }
+            .map_err(|e| MsytError::new_err(format!("Could not serialize MSBT to JSON: {:?}", e))),
+            Format::Toml => if pretty {
+                toml::to_string_pretty(msyt)
+            } else {
+                toml::to_string(msyt)
+            }
+            .map(String::into_bytes)
+            .map_err(|e| MsytError::new_err(format!("Could not serialize MSBT to TOML: {:?}", e))),
+            Format::Cbor => serde_cbor::to_vec(msyt).map_err(|e| {
+                MsytError::new_err(format!("Could not serialize MSBT to CBOR: {:?}", e))
+            }),
+        }
+    }
+
+    /// Deserializes a `Msyt` from bytes in this format.
+    fn deserialize(self, data: &[u8]) -> PyResult<Msyt> {
+        match self {
+            Format::Yaml => serde_yaml::from_slice(data)
+                .map_err(|e| MsytError::new_err(format!("Could not parse YAML to MSBT: {:?}", e))),
+            Format::Json => serde_json::from_slice(data)
+                .map_err(|e| MsytError::new_err(format!("Could not parse JSON to MSBT: {:?}", e))),
+            Format::Toml => {
+                let text = std::str::from_utf8(data).map_err(|e| {
+                    MsytError::new_err(format!("TOML input was not valid UTF-8: {:?}", e))
+                })?;
+                toml::from_str(text).map_err(|e| {
+                    MsytError::new_err(format!("Could not parse TOML to MSBT: {:?}", e))
+                })
+            }
+            Format::Cbor => serde_cbor::from_slice(data)
+                .map_err(|e| MsytError::new_err(format!("Could not parse CBOR to MSBT: {:?}", e))),
+        }
+    }
+}
+
 #[pymodule]
 fn pymsyt(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<Msbt>()?;
     m.add_wrapped(wrap_pyfunction!(create)).unwrap();
     m.add_wrapped(wrap_pyfunction!(export)).unwrap();
+    m.add_wrapped(wrap_pyfunction!(check)).unwrap();
+    m.add_wrapped(wrap_pyfunction!(inspect)).unwrap();
     Ok(())
 }
 
-/// Export an MSBT file or directory of MSBT files to YAML or JSON.
-/// 
+/// Compares re-serialized MSBT bytes against the original bytes they were parsed from. Returns
+/// `None` when the two are byte-for-byte identical, otherwise a diagnostic describing the first
+/// differing offset with a short hex context window on either side.
+fn diff_report(original: &[u8], regenerated: &[u8]) -> Option<String> {
+    let shared_len = original.len().min(regenerated.len());
+    let offset = match (0..shared_len).find(|&i| original[i] != regenerated[i]) {
+        Some(offset) => offset,
+        None if original.len() != regenerated.len() => shared_len,
+        None => return None,
+    };
+
+    const CONTEXT: usize = 8;
+    let hex_window = |data: &[u8]| -> String {
+        let start = offset.saturating_sub(CONTEXT);
+        let end = (offset + CONTEXT).min(data.len());
+        data[start..end]
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    Some(format!(
+        "MSBT differs at byte offset {} (original {} bytes, regenerated {} bytes); original=[{}] regenerated=[{}]",
+        offset,
+        original.len(),
+        regenerated.len(),
+        hex_window(original),
+        hex_window(regenerated),
+    ))
+}
+
+/// Checks whether an MSBT file (or directory of MSBT files) round-trips losslessly when parsed
+/// and then re-serialized with the given endianness.
+///
+/// :param input: The MSBT file or folder of MSBT files to check
+/// :type input: str (**must** be str, cannot be pathlike)
+/// :param big_endian: Whether to re-serialize as big endian (Wii U) or little endian (Switch).
+///                     Defaults to each file's own detected endianness.
+/// :type big_endian: bool, optional
+/// :return: `None` if `input` is a single file that round-trips losslessly, or a diagnostic
+///          string describing the first difference. If `input` is a directory, returns a dict
+///          mapping each relative path to its diagnostic string (or an error message string if
+///          that file could not be read or parsed), or `None` for files that round-trip
+///          losslessly.
+/// :rtype: Optional[str] or Dict[str, Optional[str]]
+/// :raises MsytError: Raises an `MsytError` if `input` is a single file that cannot be parsed
+///                     or re-serialized.
+#[pyfunction]
+#[text_signature = "(input, big_endian=None, /)"]
+fn check(input: String, big_endian: Option<bool>) -> PyResult<Py<PyAny>> {
+    fn check_single<P: AsRef<Path>>(
+        input: P,
+        big_endian: Option<bool>,
+    ) -> PyResult<Option<String>> {
+        let original = fs::read(&input)?;
+        let big_endian = big_endian.unwrap_or(detect_endianness(&original)?);
+        let msyt = Msyt::from_msbt_bytes(&original)
+            .map_err(|e| MsytError::new_err(format!("Could not read MSBT file: {:?}", e)))?;
+        let regenerated = msyt
+            .into_msbt_bytes(match big_endian {
+                true => Endianness::Big,
+                false => Endianness::Little,
+            })
+            .map_err(|e| MsytError::new_err(format!("Failed to serialize MSBT file: {:?}", e)))?;
+        Ok(diff_report(&original, &regenerated))
+    }
+
+    let gil = Python::acquire_gil();
+    let py = gil.python();
+    let input: PathBuf = input.into();
+    if input.is_dir() {
+        let paths: Vec<PathBuf> = glob::glob(input.join("**/*.msbt").to_str().unwrap())
+            .unwrap()
+            .filter_map(|f| f.ok())
+            .collect();
+        let results: Vec<(PathBuf, PyResult<Option<String>>)> = paths
+            .par_iter()
+            .map(|f| (f.clone(), check_single(f, big_endian)))
+            .collect();
+        let dict = PyDict::new(py);
+        for (path, result) in results {
+            let relative = path.strip_prefix(&input).unwrap_or(&path);
+            // A single unreadable/corrupt file shouldn't abort a scan of thousands of others;
+            // report its error as the dict value instead of propagating it.
+            let value = match result {
+                Ok(diff) => diff.into_py(py),
+                Err(e) => e.to_string().into_py(py),
+            };
+            dict.set_item(relative.to_string_lossy().into_owned(), value)?;
+        }
+        Ok(dict.into())
+    } else if input.is_file() {
+        Ok(check_single(&input, big_endian)?.into_py(py))
+    } else {
+        Err(MsytError::new_err(format!(
+            "{} is not a valid file or folder",
+            input.to_string_lossy()
+        )))
+    }
+}
+
+/// Number of bytes in the MSBT file header before the first section begins.
+const MSBT_HEADER_LEN: usize = 0x20;
+/// Number of bytes in a section header (magic + payload size + padding) before its payload.
+const MSBT_SECTION_HEADER_LEN: usize = 0x10;
+
+/// Reads a big- or little-endian `u32` out of `data` at `offset`, per `big_endian`.
+fn read_u32(data: &[u8], offset: usize, big_endian: bool) -> PyResult<u32> {
+    let bytes: [u8; 4] = data
+        .get(offset..offset + 4)
+        .and_then(|s| s.try_into().ok())
+        .ok_or_else(|| MsytError::new_err("Unexpected end of MSBT data"))?;
+    Ok(if big_endian {
+        u32::from_be_bytes(bytes)
+    } else {
+        u32::from_le_bytes(bytes)
+    })
+}
+
+/// Reads an MSBT file's label directory (the `LBL1` section) directly out of the raw binary
+/// structure, without decoding any entry contents, so inspecting a file's labels costs a single
+/// linear pass over the header and label table instead of a full `Msyt` decode. Returns the
+/// detected endianness alongside the labels, since both come from the same header read.
+fn scan_labels(data: &[u8]) -> PyResult<(Vec<String>, bool)> {
+    let big_endian = detect_endianness(data)?;
+    if data.len() < MSBT_HEADER_LEN || &data[0..8] != b"MsgStdBn" {
+        return Err(MsytError::new_err(
+            "Not a valid MSBT file (missing or corrupt header)",
+        ));
+    }
+    let num_sections = {
+        let bytes: [u8; 2] = data[14..16].try_into().unwrap();
+        if big_endian {
+            u16::from_be_bytes(bytes)
+        } else {
+            u16::from_le_bytes(bytes)
+        }
+    };
+
+    let mut offset = MSBT_HEADER_LEN;
+    for _ in 0..num_sections {
+        let header = data
+            .get(offset..offset + MSBT_SECTION_HEADER_LEN)
+            .ok_or_else(|| {
+                MsytError::new_err("Unexpected end of MSBT data reading a section header")
+            })?;
+        let magic = &header[0..4];
+        let size = read_u32(data, offset + 4, big_endian)? as usize;
+        let payload_start = offset + MSBT_SECTION_HEADER_LEN;
+        if magic == b"LBL1" {
+            let payload = data
+                .get(payload_start..payload_start + size)
+                .ok_or_else(|| {
+                    MsytError::new_err("Unexpected end of MSBT data reading the LBL1 section")
+                })?;
+            return Ok((read_lbl1_labels(payload, big_endian)?, big_endian));
+        }
+        // Sections are padded out to a 16-byte alignment with filler bytes.
+        offset = payload_start + ((size + 0xF) & !0xF);
+    }
+    Err(MsytError::new_err("MSBT file has no LBL1 label section"))
+}
+
+/// Parses the label entries out of an `LBL1` section's payload: a hash table of groups, each
+/// pointing at a run of `(length-prefixed name, entry index)` records.
+fn read_lbl1_labels(payload: &[u8], big_endian: bool) -> PyResult<Vec<String>> {
+    let num_groups = read_u32(payload, 0, big_endian)? as usize;
+    let mut labels = Vec::new();
+    for group in 0..num_groups {
+        let group_offset = 4 + group * 8;
+        let count = read_u32(payload, group_offset, big_endian)? as usize;
+        let mut pos = read_u32(payload, group_offset + 4, big_endian)? as usize;
+        for _ in 0..count {
+            let len = *payload
+                .get(pos)
+                .ok_or_else(|| MsytError::new_err("Unexpected end of LBL1 label entry"))?
+                as usize;
+            pos += 1;
+            let name = payload
+                .get(pos..pos + len)
+                .ok_or_else(|| MsytError::new_err("Unexpected end of LBL1 label name"))?;
+            labels.push(String::from_utf8_lossy(name).into_owned());
+            // Skip the label name and its trailing u32 entry index.
+            pos += len + 4;
+        }
+    }
+    Ok(labels)
+}
+
+/// Builds the `labels`/`count`/`endianness` metadata dict `Msbt.labels()` and `inspect()` both
+/// return, from already-computed plain Rust data.
+fn labels_dict(py: Python, labels: Vec<String>, big_endian: bool) -> PyResult<Py<PyAny>> {
+    let dict = PyDict::new(py);
+    dict.set_item("count", labels.len())?;
+    dict.set_item("labels", labels)?;
+    dict.set_item("endianness", if big_endian { "big" } else { "little" })?;
+    Ok(dict.into())
+}
+
+/// Inspects an MSBT file (or directory of MSBT files), returning lightweight metadata — the
+/// entry label names, entry count, and detected endianness — without building the full text
+/// content tree. Useful for scanning large game dumps to find which files contain a label,
+/// without paying the cost of deserializing every control-tag-laden entry.
+///
+/// :param input: The MSBT file or folder of MSBT files to inspect
+/// :type input: str (**must** be str, cannot be pathlike)
+/// :return: A dict with `labels`, `count`, and `endianness` keys for a single file, or a dict
+///          mapping each relative path to such a dict (or an error message string if that file
+///          could not be read or parsed) for a directory.
+/// :rtype: dict
+/// :raises MsytError: Raises an `MsytError` if `input` is a single file that cannot be parsed.
+#[pyfunction]
+#[text_signature = "(input, /)"]
+fn inspect(py: Python, input: String) -> PyResult<Py<PyAny>> {
+    fn inspect_single<P: AsRef<Path>>(input: P) -> PyResult<(Vec<String>, bool)> {
+        let data = fs::read(&input)?;
+        scan_labels(&data)
+    }
+
+    let input: PathBuf = input.into();
+    if input.is_dir() {
+        let paths: Vec<PathBuf> = glob::glob(input.join("**/*.msbt").to_str().unwrap())
+            .unwrap()
+            .filter_map(|f| f.ok())
+            .collect();
+        // inspect_single is pure Rust with no Python calls, so this parallel pass can run with
+        // the GIL held throughout; the PyDict construction below happens only on this thread.
+        let results: Vec<(PathBuf, PyResult<(Vec<String>, bool)>)> = paths
+            .par_iter()
+            .map(|f| (f.clone(), inspect_single(f)))
+            .collect();
+        let dict = PyDict::new(py);
+        for (path, result) in results {
+            let relative = path.strip_prefix(&input).unwrap_or(&path);
+            // A single unreadable/non-MSBT file shouldn't abort a scan of thousands of others;
+            // report its error as the dict value instead of propagating it.
+            let value = match result {
+                Ok((labels, big_endian)) => labels_dict(py, labels, big_endian)?,
+                Err(e) => e.to_string().into_py(py),
+            };
+            dict.set_item(relative.to_string_lossy().into_owned(), value)?;
+        }
+        Ok(dict.into())
+    } else if input.is_file() {
+        let (labels, big_endian) = inspect_single(&input)?;
+        labels_dict(py, labels, big_endian)
+    } else {
+        Err(MsytError::new_err(format!(
+            "{} is not a valid file or folder",
+            input.to_string_lossy()
+        )))
+    }
+}
+
+/// Export an MSBT file or directory of MSBT files to YAML, JSON, TOML, or CBOR.
+///
 /// :param input: The MSBT file or folder of MSBT files to export from
 /// :type input: str (**must** be str, cannot be pathlike)
 /// :param output: The path to export to. Defaults to same folder with new extension.
 /// :type output: str (**must** be str, cannot be pathlike), optional
-/// :param json: Whether to output as JSON instead of YAML, optional
-/// :type json: bool. Defaults to False.
+/// :param format: The format to export to: one of "yaml", "json", "toml", or "cbor", optional
+/// :type format: str. Defaults to "yaml".
+/// :param pretty: Whether to pretty-print JSON or TOML output, optional
+/// :type pretty: bool. Defaults to False.
+/// :param include: Glob patterns (relative to `input`); when given, only matching MSBT files
+///                  are exported. Only applies when `input` is a directory.
+/// :type include: List[str], optional
+/// :param exclude: Glob patterns (relative to `input`) to skip. Only applies when `input` is
+///                 a directory.
+/// :type exclude: List[str], optional
+/// :param callback: A callable invoked after each file as `callback(relative_path, count, total)`
+///                   when `input` is a directory. It runs on a worker thread and reacquires the
+///                   GIL for each call, so it should be lightweight.
+/// :type callback: Callable[[str, int, int], None], optional
 /// :raises MsytError: Raises an `MsytError` if export fails for any reason.
 #[pyfunction]
-#[text_signature = "(input, output=None, json=False)"]
-fn export(input: String, output: Option<String>, json: Option<bool>) -> PyResult<()> {
-    fn export_single<P: AsRef<Path>>(input: P, output: P, json: bool) -> PyResult<()> {
+#[text_signature = "(input, output=None, format=\"yaml\", pretty=False, include=None, exclude=None, callback=None)"]
+fn export(
+    py: Python,
+    input: String,
+    output: Option<String>,
+    format: Option<String>,
+    pretty: Option<bool>,
+    include: Option<Vec<String>>,
+    exclude: Option<Vec<String>>,
+    callback: Option<PyObject>,
+) -> PyResult<()> {
+    fn export_single<P: AsRef<Path>>(
+        input: P,
+        output: P,
+        format: Format,
+        pretty: bool,
+    ) -> PyResult<()> {
         let msyt = Msyt::from_msbt_file(&input)
             .map_err(|e| MsytError::new_err(format!("Could not read MSBT file: {:?}", e)))?;
         fs::create_dir_all(output.as_ref().parent().unwrap()).map_err(|e| {
@@ -43,17 +458,15 @@ fn export(input: String, output: Option<String>, json: Option<bool>) -> PyResult
                 e
             ))
         })?;
-        let mut file = fs::File::create(&output)?;
-        match json {
-            true => serde_json::to_writer(&mut file, &msyt).map_err(|e| {
-                MsytError::new_err(format!("Could not serialize MSBT to JSON: {:?}", e))
-            }),
-            false => serde_yaml::to_writer(&mut file, &msyt).map_err(|e| {
-                MsytError::new_err(format!("Could not serialize MSBT to YAML: {:?}", e))
-            }),
-        }
+        let bytes = format.serialize(&msyt, pretty)?;
+        fs::write(&output, bytes)?;
+        Ok(())
     }
 
+    let format = Format::parse(format.as_deref().unwrap_or("yaml"))?;
+    let pretty = pretty.unwrap_or(false);
+    let include = compile_patterns(&include)?;
+    let exclude = compile_patterns(&exclude)?;
     let input: PathBuf = input.into();
     if input.is_dir() {
         let output: PathBuf = if let Some(output) = output {
@@ -67,18 +480,26 @@ fn export(input: String, output: Option<String>, json: Option<bool>) -> PyResult
             .unwrap()
             .filter_map(|f| f.ok())
             .collect();
-        paths
-            .par_iter()
-            .try_for_each(|f| {
+        let paths = filter_paths(paths, &input, &include, &exclude);
+        let total = paths.len();
+        let completed = std::sync::atomic::AtomicUsize::new(0);
+        // Release the GIL for the whole parallel region: report_progress reacquires it per
+        // callback invocation, and any rayon worker doing so while this thread holds the GIL
+        // would deadlock against this thread waiting on `try_for_each` to finish.
+        py.allow_threads(|| {
+            paths.par_iter().try_for_each(|f| {
+                let relative = f.strip_prefix(&input).unwrap();
                 export_single(
                     f,
-                    &output
-                        .join(f.strip_prefix(&input).unwrap())
-                        .with_extension("msyt"),
-                    json.unwrap_or(false),
-                )
+                    &output.join(relative).with_extension("msyt"),
+                    format,
+                    pretty,
+                )?;
+                let count = completed.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+                report_progress(&callback, relative, count, total)
             })
-            .map_err(|e| MsytError::new_err(format!("Failed to create MSYT files: {:?}", e)))?;
+        })
+        .map_err(|e| MsytError::new_err(format!("Failed to create MSYT files: {:?}", e)))?;
         Ok(())
     } else if input.is_file() {
         let output = if let Some(output) = output {
@@ -86,7 +507,7 @@ fn export(input: String, output: Option<String>, json: Option<bool>) -> PyResult
         } else {
             input.with_extension("msyt")
         };
-        export_single(&input, &output, json.unwrap_or(false))
+        export_single(&input, &output, format, pretty)
     } else {
         Err(MsytError::new_err(format!(
             "{} is not a valid file or folder",
@@ -95,28 +516,63 @@ fn export(input: String, output: Option<String>, json: Option<bool>) -> PyResult
     }
 }
 
-/// Creates an MSBT file or directory of MSBT files from YAML or JSON.
-/// 
-/// :param input: The YAML or JSON file or folder of files to create from
+/// Creates an MSBT file or directory of MSBT files from YAML, JSON, TOML, or CBOR.
+///
+/// :param input: The MSYT file or folder of files to create from
 /// :type input: str (**must** be str, cannot be pathlike)
 /// :param big_endian: Whether to serialize as big endian
 /// :type big_endian: bool
 /// :param output: The path to output created MSBT files. Defaults to same folder with new extension.
 /// :type output: str (**must** be str, cannot be pathlike), optional
+/// :param format: The format to parse from: one of "yaml", "json", "toml", or "cbor", optional.
+///                If omitted, YAML and JSON are both tried automatically.
+/// :type format: str, optional
+/// :param include: Glob patterns (relative to `input`); when given, only matching MSYT files
+///                  are created. Only applies when `input` is a directory.
+/// :type include: List[str], optional
+/// :param exclude: Glob patterns (relative to `input`) to skip. Only applies when `input` is
+///                 a directory.
+/// :type exclude: List[str], optional
+/// :param callback: A callable invoked after each file as `callback(relative_path, count, total)`
+///                   when `input` is a directory. It runs on a worker thread and reacquires the
+///                   GIL for each call, so it should be lightweight.
+/// :type callback: Callable[[str, int, int], None], optional
 /// :raises MsytError: Raises an `MsytError` if export fails for any reason.
 #[pyfunction]
-#[text_signature = "(input, big_endian, output=None)"]
-fn create(input: String, big_endian: bool, output: Option<String>) -> PyResult<()> {
-    fn create_single<P: AsRef<Path>>(input: P, output: P, big_endian: bool) -> PyResult<()> {
-        let text = fs::read_to_string(input)?;
-        let msyt: Msyt = match serde_yaml::from_str(&text) {
-            Ok(m) => m,
-            Err(_) => serde_json::from_str(&text).map_err(|e| {
-                MsytError::new_err(format!(
-                    "Could not parse text as valid MSYT YAML or JSON: {:?}",
-                    e
-                ))
-            })?,
+#[text_signature = "(input, big_endian, output=None, format=None, include=None, exclude=None, callback=None)"]
+fn create(
+    py: Python,
+    input: String,
+    big_endian: bool,
+    output: Option<String>,
+    format: Option<String>,
+    include: Option<Vec<String>>,
+    exclude: Option<Vec<String>>,
+    callback: Option<PyObject>,
+) -> PyResult<()> {
+    fn create_single<P: AsRef<Path>>(
+        input: P,
+        output: P,
+        big_endian: bool,
+        format: Option<Format>,
+    ) -> PyResult<()> {
+        let data = fs::read(&input)?;
+        let msyt: Msyt = match format {
+            Some(format) => format.deserialize(&data)?,
+            None => {
+                let text = String::from_utf8(data).map_err(|e| {
+                    MsytError::new_err(format!("Input was not valid UTF-8: {:?}", e))
+                })?;
+                match serde_yaml::from_str(&text) {
+                    Ok(m) => m,
+                    Err(_) => serde_json::from_str(&text).map_err(|e| {
+                        MsytError::new_err(format!(
+                            "Could not parse text as valid MSYT YAML or JSON: {:?}",
+                            e
+                        ))
+                    })?,
+                }
+            }
         };
         fs::create_dir_all(output.as_ref().parent().unwrap()).map_err(|e| {
             MsytError::new_err(format!(
@@ -135,6 +591,9 @@ fn create(input: String, big_endian: bool, output: Option<String>) -> PyResult<(
         Ok(())
     }
 
+    let format = format.as_deref().map(Format::parse).transpose()?;
+    let include = compile_patterns(&include)?;
+    let exclude = compile_patterns(&exclude)?;
     let input: PathBuf = input.into();
     if input.is_dir() {
         let output: PathBuf = if let Some(output) = output {
@@ -148,18 +607,26 @@ fn create(input: String, big_endian: bool, output: Option<String>) -> PyResult<(
             .unwrap()
             .filter_map(|f| f.ok())
             .collect();
-        paths
-            .par_iter()
-            .try_for_each(|f| {
+        let paths = filter_paths(paths, &input, &include, &exclude);
+        let total = paths.len();
+        let completed = std::sync::atomic::AtomicUsize::new(0);
+        // Release the GIL for the whole parallel region: report_progress reacquires it per
+        // callback invocation, and any rayon worker doing so while this thread holds the GIL
+        // would deadlock against this thread waiting on `try_for_each` to finish.
+        py.allow_threads(|| {
+            paths.par_iter().try_for_each(|f| {
+                let relative = f.strip_prefix(&input).unwrap();
                 create_single(
                     f,
-                    &output
-                        .join(f.strip_prefix(&input).unwrap())
-                        .with_extension("msbt"),
+                    &output.join(relative).with_extension("msbt"),
                     big_endian,
-                )
+                    format,
+                )?;
+                let count = completed.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+                report_progress(&callback, relative, count, total)
             })
-            .map_err(|e| MsytError::new_err(format!("Failed to create MSBT files: {:?}", e)))?;
+        })
+        .map_err(|e| MsytError::new_err(format!("Failed to create MSBT files: {:?}", e)))?;
         Ok(())
     } else if input.is_file() {
         let output = if let Some(output) = output {
@@ -167,7 +634,7 @@ fn create(input: String, big_endian: bool, output: Option<String>) -> PyResult<(
         } else {
             input.with_extension("msbt")
         };
-        create_single(&input, &output, big_endian)
+        create_single(&input, &output, big_endian, format)
     } else {
         Err(MsytError::new_err(format!(
             "{} is not a valid file or folder",
@@ -200,6 +667,24 @@ fn create(input: String, big_endian: bool, output: Option<String>) -> PyResult<(
 /// ```
 pub struct Msbt {
     msyt: Msyt,
+    /// The raw bytes this instance was parsed from, if any, kept around for
+    /// `verify_roundtrip`. Only populated by `from_binary`.
+    original: Option<Vec<u8>>,
+    /// The endianness detected by `from_binary`, or `false` (little endian) by default for
+    /// instances built from a text/dict format that has no inherent byte order.
+    big_endian: bool,
+}
+
+/// Reads the byte order mark from an MSBT file's header (bytes 8-9) without parsing the rest
+/// of the file, so the endianness of a file can be known before doing a full decode.
+fn detect_endianness(data: &[u8]) -> PyResult<bool> {
+    match data.get(8..10) {
+        Some([0xFE, 0xFF]) => Ok(true),
+        Some([0xFF, 0xFE]) => Ok(false),
+        _ => Err(MsytError::new_err(
+            "Could not detect MSBT byte order: missing or invalid byte order mark",
+        )),
+    }
 }
 
 #[pymethods]
@@ -214,20 +699,42 @@ impl Msbt {
     #[staticmethod]
     #[text_signature = "(data, /)"]
     pub fn from_binary(data: &[u8]) -> PyResult<Self> {
+        let big_endian = detect_endianness(data)?;
         let msyt = Msyt::from_msbt_bytes(data)
             .map_err(|e| MsytError::new_err(format!("Failed to parse MSBT file: {:?}", e)))?;
-        Ok(Msbt { msyt })
+        Ok(Msbt {
+            msyt,
+            original: Some(data.to_vec()),
+            big_endian,
+        })
+    }
+
+    /// The endianness detected when this instance was parsed from binary data via
+    /// `from_binary` ("big" or "little"). Defaults to "little" for instances built from a
+    /// text or dict format, which has no inherent byte order.
+    ///
+    /// :type: str
+    #[getter]
+    pub fn endianness(&self) -> &'static str {
+        if self.big_endian {
+            "big"
+        } else {
+            "little"
+        }
     }
 
     /// Serializes this MSBT file to bytes.
     ///
-    /// :param big_endian: Whether to serialize as big endian (Wii U) or little endian (Switch)
+    /// :param big_endian: Whether to serialize as big endian (Wii U) or little endian (Switch).
+    ///                     Defaults to the endianness detected by `from_binary`, or little
+    ///                     endian if this instance has none.
     /// :type big_endian: bool, optional
     /// :return: Returns the MSBT file as a bytes object.
     /// :rtype: bytes
     /// :raises MsytError: Raises an `MsytError` if serialization fails.
-    #[text_signature = "($self, big_endian, /)"]
-    pub fn to_binary(&self, big_endian: bool) -> PyResult<Py<PyAny>> {
+    #[text_signature = "($self, big_endian=None, /)"]
+    pub fn to_binary(&self, big_endian: Option<bool>) -> PyResult<Py<PyAny>> {
+        let big_endian = big_endian.unwrap_or(self.big_endian);
         let gil = Python::acquire_gil();
         let py = gil.python();
         Ok(PyBytes::new(
@@ -246,6 +753,54 @@ impl Msbt {
         .into())
     }
 
+    /// Returns lightweight metadata about this MSBT file's structure — the entry label names,
+    /// entry count, and detected endianness — without converting entry contents to text.
+    ///
+    /// :return: Returns a dict with `labels`, `count`, and `endianness` keys.
+    /// :rtype: dict
+    #[text_signature = "($self)"]
+    pub fn labels(&self) -> PyResult<Py<PyAny>> {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        // When we have the original binary, scan its LBL1 section directly instead of reading
+        // back out of the fully decoded entry map, so this stays a lightweight operation.
+        let (labels, big_endian) = match &self.original {
+            Some(data) => scan_labels(data)?,
+            None => (self.msyt.entries.keys().cloned().collect(), self.big_endian),
+        };
+        labels_dict(py, labels, big_endian)
+    }
+
+    /// Checks whether re-serializing this MSBT round-trips losslessly back to the binary data
+    /// it was parsed from. Only available on instances created with `from_binary`.
+    ///
+    /// :param big_endian: Whether to re-serialize as big endian (Wii U) or little endian
+    ///                     (Switch). Defaults to the endianness detected by `from_binary`.
+    /// :type big_endian: bool, optional
+    /// :return: `None` if the round-trip is byte-for-byte identical, otherwise a diagnostic
+    ///          string describing the first differing offset.
+    /// :rtype: Optional[str]
+    /// :raises MsytError: Raises an `MsytError` if this instance was not parsed with
+    ///                     `from_binary`, or if re-serialization fails.
+    #[text_signature = "($self, big_endian=None, /)"]
+    pub fn verify_roundtrip(&self, big_endian: Option<bool>) -> PyResult<Option<String>> {
+        let big_endian = big_endian.unwrap_or(self.big_endian);
+        let original = self.original.as_ref().ok_or_else(|| {
+            MsytError::new_err(
+                "verify_roundtrip requires an Msbt parsed from binary data via Msbt.from_binary",
+            )
+        })?;
+        let regenerated = self
+            .msyt
+            .clone()
+            .into_msbt_bytes(match big_endian {
+                true => Endianness::Big,
+                false => Endianness::Little,
+            })
+            .map_err(|e| MsytError::new_err(format!("Failed to serialize MSBT file: {:?}", e)))?;
+        Ok(diff_report(original, &regenerated))
+    }
+
     /// Generates a YAML representation of this MSBT file.
     ///
     /// :return: Returns the MSBT as a YAML string.
@@ -282,6 +837,8 @@ impl Msbt {
             msyt: serde_yaml::from_str(&yaml).map_err(|e| {
                 MsytError::new_err(format!("Could not parse YAML to MSBT: {:?}", e))
             })?,
+            original: None,
+            big_endian: false,
         })
     }
 
@@ -299,6 +856,72 @@ impl Msbt {
             msyt: serde_json::from_str(&json).map_err(|e| {
                 MsytError::new_err(format!("Could not parse JSON to MSBT: {:?}", e))
             })?,
+            original: None,
+            big_endian: false,
+        })
+    }
+
+    /// Generates a TOML representation of this MSBT file.
+    ///
+    /// :return: Returns the MSBT as a TOML string.
+    /// :rtype: str
+    /// :raises MsytError: Raises an `MsytError` if serialization fails.
+    #[text_signature = "($self)"]
+    pub fn to_toml(&self) -> PyResult<String> {
+        toml::to_string(&self.msyt)
+            .map_err(|e| MsytError::new_err(format!("Failed to dump MSBT to TOML: {:?}", e)))
+    }
+
+    /// Parses an MSBT file from a TOML representation.
+    ///
+    /// :param toml: The text of the TOML to parse.
+    /// :type toml: str
+    /// :return: Returns a parsed `pymsyt.Msbt` from the TOML text.
+    /// :rtype: `pymsyt.Msbt`
+    /// :raises MsytError: Raises an `MsytError` if parsing fails.
+    #[staticmethod]
+    #[text_signature = "(toml, /)"]
+    pub fn from_toml(toml: String) -> PyResult<Self> {
+        Ok(Self {
+            msyt: toml::from_str(&toml).map_err(|e| {
+                MsytError::new_err(format!("Could not parse TOML to MSBT: {:?}", e))
+            })?,
+            original: None,
+            big_endian: false,
+        })
+    }
+
+    /// Generates a CBOR representation of this MSBT file. Unlike the other text formats,
+    /// CBOR is a binary format, so this returns `bytes` rather than `str`.
+    ///
+    /// :return: Returns the MSBT as CBOR bytes.
+    /// :rtype: bytes
+    /// :raises MsytError: Raises an `MsytError` if serialization fails.
+    #[text_signature = "($self)"]
+    pub fn to_cbor(&self) -> PyResult<Py<PyAny>> {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        let bytes = serde_cbor::to_vec(&self.msyt)
+            .map_err(|e| MsytError::new_err(format!("Failed to dump MSBT to CBOR: {:?}", e)))?;
+        Ok(PyBytes::new(py, &bytes).into())
+    }
+
+    /// Parses an MSBT file from a CBOR representation.
+    ///
+    /// :param cbor: The CBOR bytes to parse.
+    /// :type cbor: bytes
+    /// :return: Returns a parsed `pymsyt.Msbt` from the CBOR bytes.
+    /// :rtype: `pymsyt.Msbt`
+    /// :raises MsytError: Raises an `MsytError` if parsing fails.
+    #[staticmethod]
+    #[text_signature = "(cbor, /)"]
+    pub fn from_cbor(cbor: &[u8]) -> PyResult<Self> {
+        Ok(Self {
+            msyt: serde_cbor::from_slice(cbor).map_err(|e| {
+                MsytError::new_err(format!("Could not parse CBOR to MSBT: {:?}", e))
+            })?,
+            original: None,
+            big_endian: false,
         })
     }
 
@@ -311,12 +934,9 @@ impl Msbt {
     pub fn to_dict(&self) -> PyResult<Py<PyAny>> {
         let gil = Python::acquire_gil();
         let py = gil.python();
-        let text = self.to_json()?;
-        let json = PyModule::import(py, "json")?;
-        let dict = json.call("loads", (text,), None).map_err(|e| {
+        pythonize::pythonize(py, &self.msyt).map_err(|e| {
             MsytError::new_err(format!("Could not serialize MSBT to Python dict: {:?}", e))
-        })?;
-        Ok(Py::from(dict))
+        })
     }
 
     /// Parses an MSBT file from a Python dictionary.
@@ -329,11 +949,126 @@ impl Msbt {
     #[staticmethod]
     #[text_signature = "(dict, /)"]
     pub fn from_dict(dict: &PyDict) -> PyResult<Self> {
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        let json = PyModule::import(py, "json")?;
-        let res = json.call("dumps", (dict,), None)?;
-        let text = res.downcast::<PyString>()?;
-        Self::from_json(text.to_string())
+        Ok(Self {
+            msyt: pythonize::depythonize(dict).map_err(|e| {
+                MsytError::new_err(format!("Could not parse Python dict to MSBT: {:?}", e))
+            })?,
+            original: None,
+            big_endian: false,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a minimal little-endian MSBT file containing a single LBL1 section with the given
+    /// labels, one group holding all of them, laid out exactly like a real file.
+    fn build_msbt(labels: &[&str]) -> Vec<u8> {
+        let mut payload = Vec::new();
+        payload.extend_from_slice(&1u32.to_le_bytes()); // num_groups
+        let entries_offset = 4 + 1 * 8;
+        payload.extend_from_slice(&(labels.len() as u32).to_le_bytes()); // group count
+        payload.extend_from_slice(&(entries_offset as u32).to_le_bytes()); // group offset
+        for (i, label) in labels.iter().enumerate() {
+            payload.push(label.len() as u8);
+            payload.extend_from_slice(label.as_bytes());
+            payload.extend_from_slice(&(i as u32).to_le_bytes());
+        }
+
+        let mut file = Vec::new();
+        file.extend_from_slice(b"MsgStdBn");
+        file.extend_from_slice(&[0xFF, 0xFE]); // little-endian BOM
+        file.extend_from_slice(&[0, 0, 0, 0]); // unknown(2) + encoding(1) + version(1)
+        file.extend_from_slice(&1u16.to_le_bytes()); // num_sections, at offset 0x0E
+        file.resize(MSBT_HEADER_LEN, 0);
+
+        file.extend_from_slice(b"LBL1");
+        file.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+        file.extend_from_slice(&[0; 8]); // section header padding
+        file.extend_from_slice(&payload);
+        // Pad the section payload out to a 16-byte boundary, like a real file would be.
+        while file.len() % 16 != 0 {
+            file.push(0);
+        }
+        file
+    }
+
+    #[test]
+    fn read_u32_respects_endianness() {
+        let data = [0x01, 0x02, 0x03, 0x04];
+        assert_eq!(read_u32(&data, 0, false).unwrap(), 0x04030201);
+        assert_eq!(read_u32(&data, 0, true).unwrap(), 0x01020304);
+    }
+
+    #[test]
+    fn read_u32_rejects_truncated_data() {
+        assert!(read_u32(&[0x01, 0x02], 0, false).is_err());
+    }
+
+    #[test]
+    fn scan_labels_reads_single_group() {
+        let file = build_msbt(&["Hello", "World"]);
+        let (labels, big_endian) = scan_labels(&file).unwrap();
+        assert_eq!(labels, vec!["Hello".to_string(), "World".to_string()]);
+        assert!(!big_endian);
+    }
+
+    #[test]
+    fn scan_labels_rejects_missing_header() {
+        assert!(scan_labels(&[0; 4]).is_err());
+    }
+
+    #[test]
+    fn scan_labels_rejects_truncated_section() {
+        let mut file = build_msbt(&["Hello"]);
+        // The trailing alignment padding is at most 15 bytes, so cutting 16 always reaches into
+        // the LBL1 payload itself.
+        file.truncate(file.len() - 16);
+        assert!(scan_labels(&file).is_err());
+    }
+
+    #[test]
+    fn scan_labels_skips_padding_to_find_a_later_section() {
+        // An earlier, non-LBL1 section whose payload isn't a multiple of 16 bytes checks that
+        // the padding-alignment skip in scan_labels lands on the next section header correctly.
+        let mut file = Vec::new();
+        file.extend_from_slice(b"MsgStdBn");
+        file.extend_from_slice(&[0xFF, 0xFE]);
+        file.extend_from_slice(&[0, 0, 0, 0]); // unknown(2) + encoding(1) + version(1)
+        file.extend_from_slice(&2u16.to_le_bytes()); // num_sections, at offset 0x0E
+        file.resize(MSBT_HEADER_LEN, 0);
+
+        file.extend_from_slice(b"ATR1");
+        file.extend_from_slice(&3u32.to_le_bytes());
+        file.extend_from_slice(&[0; 8]);
+        file.extend_from_slice(&[0xAA, 0xBB, 0xCC]);
+        while file.len() % 16 != 0 {
+            file.push(0);
+        }
+
+        let lbl1 = build_msbt(&["Only"]);
+        file.extend_from_slice(&lbl1[MSBT_HEADER_LEN..]);
+
+        let (labels, _) = scan_labels(&file).unwrap();
+        assert_eq!(labels, vec!["Only".to_string()]);
+    }
+
+    #[test]
+    fn diff_report_is_none_for_identical_bytes() {
+        assert_eq!(diff_report(b"abcdef", b"abcdef"), None);
+    }
+
+    #[test]
+    fn diff_report_finds_first_differing_offset() {
+        let report = diff_report(b"abcdef", b"abXdef").unwrap();
+        assert!(report.contains("offset 2"), "report was: {}", report);
+    }
+
+    #[test]
+    fn diff_report_flags_length_mismatch() {
+        let report = diff_report(b"abcdef", b"abcde").unwrap();
+        assert!(report.contains("offset 5"), "report was: {}", report);
     }
 }